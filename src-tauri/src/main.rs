@@ -16,6 +16,12 @@ use std::fs::OpenOptions;
 use std::io::Write;
 use chrono::Local;
 use dotenv::dotenv;
+use async_trait::async_trait;
+use once_cell::sync::OnceCell;
+use std::sync::Mutex;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use unicode_normalization::UnicodeNormalization;
 
 #[derive(Debug, serde::Deserialize)]
 struct QwenResponse {
@@ -30,6 +36,295 @@ struct Output {
     text: String,
 }
 
+#[derive(Debug, serde::Deserialize)]
+struct DeepLResponse {
+    translations: Vec<DeepLTranslation>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct DeepLTranslation {
+    #[serde(default)]
+    #[allow(dead_code)]
+    detected_source_language: Option<String>,
+    text: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct GoogleResponse {
+    data: GoogleData,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct GoogleData {
+    translations: Vec<GoogleTranslation>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct GoogleTranslation {
+    #[serde(rename = "translatedText")]
+    translated_text: String,
+}
+
+// Detects the source language shared by every translator backend.
+fn detect_language(text: &str) -> Result<Language, Box<dyn Error>> {
+    let detector: LanguageDetector = LanguageDetectorBuilder::from_languages(
+        &[Language::English, Language::Chinese, Language::Japanese, Language::Korean]
+    ).build();
+
+    detector.detect_language_of(text).ok_or_else(|| "Could not detect language".into())
+}
+
+// Returns the English name `qwen-max` expects in its system prompt.
+fn qwen_language_name(lang: Language) -> &'static str {
+    match lang {
+        Language::Chinese => "Chinese",
+        Language::Japanese => "Japanese",
+        Language::Korean => "Korean",
+        _ => "English",
+    }
+}
+
+fn deepl_language_code(lang: Language) -> &'static str {
+    match lang {
+        Language::Chinese => "ZH",
+        Language::Japanese => "JA",
+        Language::Korean => "KO",
+        _ => "EN",
+    }
+}
+
+fn google_language_code(lang: Language) -> &'static str {
+    match lang {
+        Language::Chinese => "zh",
+        Language::Japanese => "ja",
+        Language::Korean => "ko",
+        _ => "en",
+    }
+}
+
+// An error from a translation backend that knows whether it's worth retrying,
+// so callers don't have to guess by pattern-matching the rendered message.
+#[derive(Debug)]
+struct TranslationError {
+    message: String,
+    retryable: bool,
+}
+
+impl std::fmt::Display for TranslationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for TranslationError {}
+
+// Detects the source language and returns the text unchanged if it already
+// matches `target`, so each backend can bail out before making an API call.
+fn short_circuit_if_already_target(text: &str, target: Language) -> Result<Option<String>, Box<dyn Error>> {
+    let detected_language = detect_language(text)?;
+    log_to_file(&format!("Detected language: {:?}", detected_language));
+
+    if detected_language == target {
+        return Ok(Some(text.to_string()));
+    }
+
+    Ok(None)
+}
+
+// Reads the API key every backend needs, turning a missing var into the same
+// logged, user-facing error each backend used to construct by hand.
+fn require_api_key(var_name: &str) -> Result<String, Box<dyn Error>> {
+    env::var(var_name).map_err(|e| {
+        log_to_file(&format!("Failed to get {}: {}", var_name, e));
+        format!("{} environment variable not set", var_name).into()
+    })
+}
+
+// Turns a non-2xx HTTP response into a `TranslationError`, marking 429s and
+// server errors as retryable.
+fn require_success(status: reqwest::StatusCode, body: &str) -> Result<(), Box<dyn Error>> {
+    if status.is_success() {
+        return Ok(());
+    }
+
+    Err(Box::new(TranslationError {
+        retryable: status.as_u16() == 429 || status.is_server_error(),
+        message: format!("HTTP {}: {}", status.as_u16(), body),
+    }))
+}
+
+/// A translation backend capable of translating text into a target language.
+#[async_trait]
+trait Translator {
+    async fn translate(&self, text: &str, target: Language) -> Result<String, Box<dyn Error>>;
+}
+
+// DashScope's own application-level error codes for its text-generation API
+// (returned with HTTP 200, so these need separate handling from the status check).
+fn is_dashscope_code_retryable(code: &str) -> bool {
+    code.eq_ignore_ascii_case("Throttling")
+        || code.starts_with("Throttling.")
+        || code.eq_ignore_ascii_case("RequestTimeOut")
+}
+
+struct QwenTranslator;
+
+#[async_trait]
+impl Translator for QwenTranslator {
+    async fn translate(&self, text: &str, target: Language) -> Result<String, Box<dyn Error>> {
+        if let Some(unchanged) = short_circuit_if_already_target(text, target)? {
+            return Ok(unchanged);
+        }
+
+        let api_key = require_api_key("QWEN_API_KEY")?;
+
+        let client = Client::new();
+
+        let response = client
+            .post("https://dashscope.aliyuncs.com/api/v1/services/aigc/text-generation/generation")
+            .header("Authorization", format!("Bearer {}", api_key))
+            .header("Content-Type", "application/json")
+            .json(&json!({
+                "model": "qwen-max",
+                "input": {
+                    "messages": [
+                        {
+                            "role": "system",
+                            "content": format!("You are a translator. Translate the following text to {}. Only respond with the translation, no explanations or additional text.", qwen_language_name(target))
+                        },
+                        {
+                            "role": "user",
+                            "content": text
+                        }
+                    ]
+                }
+            }))
+            .send()
+            .await?;
+
+        let status = response.status();
+        let response_text = response.text().await?;
+        require_success(status, &response_text)?;
+
+        let parsed_response: Result<QwenResponse, _> = serde_json::from_str(&response_text);
+
+        match parsed_response {
+            Ok(response) => {
+                if let Some(code) = response.code {
+                    if code != "200" {
+                        let message = response.message.unwrap_or_default();
+                        return Err(Box::new(TranslationError {
+                            retryable: is_dashscope_code_retryable(&code),
+                            message: format!("API Error ({}): {}", code, message),
+                        }));
+                    }
+                }
+                Ok(response.output.text.trim().to_string())
+            },
+            Err(e) => {
+                eprintln!("Response text: {}", response_text);
+                Err(format!("Failed to parse API response: {}", e).into())
+            }
+        }
+    }
+}
+
+struct DeepLTranslator;
+
+#[async_trait]
+impl Translator for DeepLTranslator {
+    async fn translate(&self, text: &str, target: Language) -> Result<String, Box<dyn Error>> {
+        if let Some(unchanged) = short_circuit_if_already_target(text, target)? {
+            return Ok(unchanged);
+        }
+
+        let api_key = require_api_key("DEEPL_API_KEY")?;
+
+        let client = Client::new();
+
+        let response = client
+            .post("https://api-free.deepl.com/v2/translate")
+            .header("Authorization", format!("DeepL-Auth-Key {}", api_key))
+            .form(&[
+                ("text", text),
+                ("target_lang", deepl_language_code(target)),
+            ])
+            .send()
+            .await?;
+
+        let status = response.status();
+        let response_text = response.text().await?;
+        require_success(status, &response_text)?;
+
+        let parsed_response: Result<DeepLResponse, _> = serde_json::from_str(&response_text);
+
+        match parsed_response {
+            Ok(mut response) if !response.translations.is_empty() => {
+                Ok(response.translations.remove(0).text.trim().to_string())
+            },
+            Ok(_) => Err("DeepL response contained no translations".into()),
+            Err(e) => {
+                eprintln!("Response text: {}", response_text);
+                Err(format!("Failed to parse API response: {}", e).into())
+            }
+        }
+    }
+}
+
+struct GoogleTranslator;
+
+#[async_trait]
+impl Translator for GoogleTranslator {
+    async fn translate(&self, text: &str, target: Language) -> Result<String, Box<dyn Error>> {
+        if let Some(unchanged) = short_circuit_if_already_target(text, target)? {
+            return Ok(unchanged);
+        }
+
+        let api_key = require_api_key("GOOGLE_TRANSLATE_API_KEY")?;
+
+        let client = Client::new();
+
+        let response = client
+            .post("https://translation.googleapis.com/language/translate/v2")
+            .query(&[("key", api_key.as_str())])
+            .form(&[
+                ("q", text),
+                ("target", google_language_code(target)),
+                ("format", "text"),
+            ])
+            .send()
+            .await?;
+
+        let status = response.status();
+        let response_text = response.text().await?;
+        require_success(status, &response_text)?;
+
+        let parsed_response: Result<GoogleResponse, _> = serde_json::from_str(&response_text);
+
+        match parsed_response {
+            Ok(mut response) if !response.data.translations.is_empty() => {
+                Ok(response.data.translations.remove(0).translated_text.trim().to_string())
+            },
+            Ok(_) => Err("Google Translate response contained no translations".into()),
+            Err(e) => {
+                eprintln!("Response text: {}", response_text);
+                Err(format!("Failed to parse API response: {}", e).into())
+            }
+        }
+    }
+}
+
+// Picks the translator backend from `TRANSLATOR_BACKEND` (default: qwen).
+fn build_translator() -> Box<dyn Translator> {
+    let backend = env::var("TRANSLATOR_BACKEND").unwrap_or_else(|_| "qwen".to_string());
+
+    match backend.to_lowercase().as_str() {
+        "deepl" => Box::new(DeepLTranslator),
+        "google" => Box::new(GoogleTranslator),
+        _ => Box::new(QwenTranslator),
+    }
+}
+
 // 添加日志记录函数
 fn log_to_file(message: &str) {
     let now = Local::now();
@@ -45,77 +340,182 @@ fn log_to_file(message: &str) {
     }
 }
 
-async fn translate_text(text: &str) -> Result<String, Box<dyn Error>> {
-    log_to_file(&format!("Translating text: {}", text));
-    
-    let detector: LanguageDetector = LanguageDetectorBuilder::from_languages(
-        &[Language::English, Language::Chinese, Language::Japanese, Language::Korean]
-    ).build();
-    
-    let detected_language = detector.detect_language_of(text)
-        .ok_or("Could not detect language")?;
-    
-    log_to_file(&format!("Detected language: {:?}", detected_language));
-    
-    if detected_language == Language::English {
-        return Ok(text.to_string());
+const MAX_RETRY_ATTEMPTS: u32 = 4;
+const INITIAL_BACKOFF_MS: u64 = 500;
+const MAX_BACKOFF_MS: u64 = 4000;
+
+// Transient failures (rate limits, server errors, network blips) are worth retrying;
+// anything else (bad API key, unparsable response) is not. Backends report this via
+// `TranslationError::retryable`; errors we didn't construct ourselves (e.g. a reqwest
+// network failure surfaced through `?`) fall back to matching the rendered message.
+fn is_retryable_error(err: &(dyn Error + 'static)) -> bool {
+    if let Some(translation_err) = err.downcast_ref::<TranslationError>() {
+        return translation_err.retryable;
     }
 
-    let api_key = match env::var("QWEN_API_KEY") {
-        Ok(key) => key,
-        Err(e) => {
-            log_to_file(&format!("Failed to get QWEN_API_KEY in translate_text: {}", e));
-            return Err("QWEN_API_KEY environment variable not set".into());
+    let lower = err.to_string().to_lowercase();
+    lower.contains("error sending request") || lower.contains("timed out") || lower.contains("connection")
+}
+
+async fn translate_with_retry(
+    translator: &dyn Translator,
+    text: &str,
+    target_lang: Language,
+) -> Result<String, Box<dyn Error>> {
+    let mut attempt = 0;
+    let mut backoff_ms = INITIAL_BACKOFF_MS;
+
+    loop {
+        match translator.translate(text, target_lang).await {
+            Ok(result) => return Ok(result),
+            Err(e) if attempt + 1 < MAX_RETRY_ATTEMPTS && is_retryable_error(e.as_ref()) => {
+                attempt += 1;
+                log_to_file(&format!(
+                    "Retryable error translating '{}' (attempt {}/{}): {}. Backing off {}ms",
+                    text, attempt, MAX_RETRY_ATTEMPTS, e, backoff_ms
+                ));
+                tokio::time::sleep(std::time::Duration::from_millis(backoff_ms)).await;
+                backoff_ms = (backoff_ms * 2).min(MAX_BACKOFF_MS);
+            },
+            Err(e) => return Err(e),
         }
+    }
+}
+
+// Translates `text`, consulting and updating the in-memory cache, without
+// flushing it to disk. Callers that issue many translations in a row (batch
+// commands) should call this directly and flush once at the end via
+// `flush_translation_cache`, instead of paying for a full cache rewrite per item.
+async fn translate_text_cached(text: &str, target_lang: Language) -> Result<String, Box<dyn Error>> {
+    log_to_file(&format!("Translating text: {} (target: {:?})", text, target_lang));
+
+    let cache_key = (text.to_string(), target_lang);
+
+    if let Some(cached) = translation_cache().lock().unwrap().get(&cache_key) {
+        log_to_file(&format!("Cache hit for: {} (target: {:?})", text, target_lang));
+        return Ok(cached.clone());
+    }
+
+    let translator = build_translator();
+    let translation = translate_with_retry(translator.as_ref(), text, target_lang).await?;
+
+    translation_cache().lock().unwrap().insert(cache_key, translation.clone());
+
+    Ok(translation)
+}
+
+// Persists the in-memory cache to disk. Cheap to call once per batch; expensive
+// to call per item, since it re-serializes and rewrites the whole cache file.
+fn flush_translation_cache() {
+    save_cache_to_disk(&translation_cache().lock().unwrap());
+}
+
+// Parses a user-supplied target language against the languages this app can detect/translate.
+fn parse_target_lang(value: &str) -> Result<Language, String> {
+    match value.trim().to_lowercase().as_str() {
+        "english" | "en" => Ok(Language::English),
+        "chinese" | "zh" => Ok(Language::Chinese),
+        "japanese" | "ja" => Ok(Language::Japanese),
+        "korean" | "ko" => Ok(Language::Korean),
+        other => Err(format!("Unsupported target language: {}", other)),
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct CacheEntry {
+    text: String,
+    target_lang: String,
+    translation: String,
+}
+
+type TranslationCache = HashMap<(String, Language), String>;
+
+static CACHE: OnceCell<Mutex<TranslationCache>> = OnceCell::new();
+
+fn cache_file_path() -> PathBuf {
+    std::env::temp_dir().join("translator_cache.json")
+}
+
+fn load_cache_from_disk() -> TranslationCache {
+    let mut cache = TranslationCache::new();
+
+    let contents = match std::fs::read_to_string(cache_file_path()) {
+        Ok(contents) => contents,
+        Err(_) => return cache,
     };
 
-    let client = Client::new();
-
-    let response = client
-        .post("https://dashscope.aliyuncs.com/api/v1/services/aigc/text-generation/generation")
-        .header("Authorization", format!("Bearer {}", api_key))
-        .header("Content-Type", "application/json")
-        .json(&json!({
-            "model": "qwen-max",
-            "input": {
-                "messages": [
-                    {
-                        "role": "system",
-                        "content": "You are a translator. Translate the following text to English. Only respond with the translation, no explanations or additional text."
-                    },
-                    {
-                        "role": "user",
-                        "content": text
-                    }
-                ]
+    match serde_json::from_str::<Vec<CacheEntry>>(&contents) {
+        Ok(entries) => {
+            for entry in entries {
+                if let Ok(lang) = parse_target_lang(&entry.target_lang) {
+                    cache.insert((entry.text, lang), entry.translation);
+                }
             }
-        }))
-        .send()
-        .await?;
+        },
+        Err(e) => log_to_file(&format!("Failed to parse translation cache file: {}", e)),
+    }
 
-    let response_text = response.text().await?;
-    let parsed_response: Result<QwenResponse, _> = serde_json::from_str(&response_text);
-    
-    match parsed_response {
-        Ok(response) => {
-            if let Some(code) = response.code {
-                if code != "200" {
-                    return Err(format!("API Error: {}", response.message.unwrap_or_default()).into());
-                }
+    cache
+}
+
+fn save_cache_to_disk(cache: &TranslationCache) {
+    let entries: Vec<CacheEntry> = cache
+        .iter()
+        .map(|((text, lang), translation)| CacheEntry {
+            text: text.clone(),
+            target_lang: format!("{:?}", lang),
+            translation: translation.clone(),
+        })
+        .collect();
+
+    match serde_json::to_string(&entries) {
+        Ok(json) => {
+            if let Err(e) = std::fs::write(cache_file_path(), json) {
+                log_to_file(&format!("Failed to write translation cache file: {}", e));
             }
-            Ok(response.output.text.trim().to_string())
         },
-        Err(e) => {
-            eprintln!("Response text: {}", response_text);
-            Err(format!("Failed to parse API response: {}", e).into())
-        }
+        Err(e) => log_to_file(&format!("Failed to serialize translation cache: {}", e)),
     }
 }
 
+fn translation_cache() -> &'static Mutex<TranslationCache> {
+    CACHE.get_or_init(|| Mutex::new(load_cache_from_disk()))
+}
+
 #[tauri::command]
-async fn translate_filename(filename: &str) -> Result<String, String> {
+fn clear_translation_cache() -> Result<(), String> {
+    translation_cache().lock().unwrap().clear();
+
+    match std::fs::remove_file(cache_file_path()) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+// Strips the characters reserved on Windows (`< > : " / \ | ? *`), trims
+// trailing dots/spaces, and NFC-normalizes the result. Control characters and
+// reserved device names such as `CON` are left as they are.
+fn sanitize_filename_component(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !matches!(c, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*'))
+        .collect();
+
+    let trimmed = cleaned.trim_end_matches(['.', ' ']);
+    let sanitized = if trimmed.is_empty() { "_" } else { trimmed };
+
+    sanitized.nfc().collect::<String>()
+}
+
+// Shared by every command that needs to translate a filename: translate_filename,
+// translate_path, translate_filenames, and translate_document. Takes the already-
+// parsed target language directly, and leaves disk persistence to the caller (via
+// `translate_text_cached`), so batch callers can flush the cache once instead of
+// per file.
+async fn translate_filename_lang(filename: &str, target_lang: Language) -> Result<String, String> {
     log_to_file(&format!("Attempting to translate filename: {}", filename));
-    
+
     let parts: Vec<&str> = filename.rsplitn(2, '.').collect();
     let (name, ext) = match parts.as_slice() {
         [ext, name] => {
@@ -129,11 +529,11 @@ async fn translate_filename(filename: &str) -> Result<String, String> {
         _ => unreachable!(),
     };
 
-    match translate_text(name).await {
+    match translate_text_cached(name, target_lang).await {
         Ok(translated_name) => {
-            let translated_name = translated_name.replace(" ", "_");
+            let translated_name = sanitize_filename_component(&translated_name.replace(' ', "_"));
             let result = match ext {
-                Some(ext) => format!("{}.{}", translated_name, ext),
+                Some(ext) => format!("{}.{}", translated_name, sanitize_filename_component(ext)),
                 None => translated_name,
             };
             log_to_file(&format!("Successfully translated to: {}", result));
@@ -147,6 +547,253 @@ async fn translate_filename(filename: &str) -> Result<String, String> {
     }
 }
 
+#[tauri::command]
+async fn translate_filename(filename: &str, target_lang: Option<String>) -> Result<String, String> {
+    let target_lang = match target_lang {
+        Some(lang) => parse_target_lang(&lang)?,
+        None => Language::English,
+    };
+
+    let result = translate_filename_lang(filename, target_lang).await;
+    flush_translation_cache();
+    result
+}
+
+// Translates a `/`-separated relative path component by component, so
+// directory segments and the final filename are each translated on their own
+// (e.g. `照片/文档.txt` -> `photos/document.txt`).
+#[tauri::command]
+async fn translate_path(path: &str, target_lang: Option<String>) -> Result<String, String> {
+    log_to_file(&format!("Attempting to translate path: {}", path));
+
+    let target_lang = match target_lang {
+        Some(lang) => parse_target_lang(&lang)?,
+        None => Language::English,
+    };
+
+    let components: Vec<&str> = path.split('/').collect();
+    let last_index = components.len().saturating_sub(1);
+    let mut translated_components = Vec::with_capacity(components.len());
+
+    for (i, component) in components.into_iter().enumerate() {
+        if component.is_empty() {
+            translated_components.push(component.to_string());
+            continue;
+        }
+
+        let translated = if i == last_index {
+            translate_filename_lang(component, target_lang).await?
+        } else {
+            let translated_name = translate_text_cached(component, target_lang)
+                .await
+                .map_err(|e| e.to_string())?;
+            sanitize_filename_component(&translated_name.replace(' ', "_"))
+        };
+
+        translated_components.push(translated);
+    }
+
+    flush_translation_cache();
+    Ok(translated_components.join("/"))
+}
+
+// Cap in-flight translation requests so large folders don't open hundreds of sockets at once.
+const MAX_CONCURRENT_TRANSLATIONS: usize = 4;
+
+#[tauri::command]
+async fn translate_filenames(
+    filenames: Vec<String>,
+    target_lang: Option<String>,
+) -> Result<Vec<Result<String, String>>, String> {
+    log_to_file(&format!("Translating {} filenames as a batch", filenames.len()));
+
+    let target_lang = match target_lang {
+        Some(lang) => parse_target_lang(&lang)?,
+        None => Language::English,
+    };
+
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(MAX_CONCURRENT_TRANSLATIONS));
+
+    let tasks = filenames.into_iter().map(|filename| {
+        let semaphore = semaphore.clone();
+        async move {
+            let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+            translate_filename_lang(&filename, target_lang).await
+        }
+    });
+
+    let results = futures::future::join_all(tasks).await;
+    flush_translation_cache();
+
+    Ok(results)
+}
+
+// Keep translated chunks comfortably under the model's token limit, counted in
+// Unicode scalar values (not bytes) — a byte cap would chop CJK documents to a
+// fraction of the intended chunk size, since each CJK character is multiple bytes.
+const MAX_CHUNK_CHARS: usize = 2000;
+
+// Splits `text` into chunks no larger than `max_chars`, breaking on paragraph
+// boundaries where possible so translations stay coherent. Each chunk is paired
+// with whether it actually begins a new paragraph in the source text, as opposed
+// to being a forced mid-paragraph continuation (when a single paragraph exceeds
+// `max_chars`) — callers need this to avoid inserting a blank line where the
+// source never had one.
+fn split_into_chunks(text: &str, max_chars: usize) -> Vec<(String, bool)> {
+    let mut chunks: Vec<(String, bool)> = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0usize;
+    let mut current_starts_paragraph = true;
+
+    for paragraph in text.split("\n\n") {
+        let paragraph_len = paragraph.chars().count();
+
+        if !current.is_empty() && current_len + paragraph_len + 2 > max_chars {
+            chunks.push((std::mem::take(&mut current), current_starts_paragraph));
+            current_len = 0;
+            current_starts_paragraph = true;
+        }
+
+        if !current.is_empty() {
+            current.push_str("\n\n");
+            current_len += 2;
+        }
+        current.push_str(paragraph);
+        current_len += paragraph_len;
+
+        while current_len > max_chars {
+            let split_at = current
+                .char_indices()
+                .nth(max_chars)
+                .map(|(i, _)| i)
+                .unwrap_or(current.len());
+            chunks.push((current[..split_at].to_string(), current_starts_paragraph));
+            current = current[split_at..].to_string();
+            current_len = current.chars().count();
+            current_starts_paragraph = false;
+        }
+    }
+
+    if !current.is_empty() {
+        chunks.push((current, current_starts_paragraph));
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod split_into_chunks_tests {
+    use super::*;
+
+    #[test]
+    fn force_splits_a_single_paragraph_that_exceeds_the_limit_with_no_blank_line() {
+        let paragraph = "a".repeat(25);
+
+        let chunks = split_into_chunks(&paragraph, 10);
+
+        assert_eq!(
+            chunks,
+            vec![
+                ("a".repeat(10), true),
+                ("a".repeat(10), false),
+                ("a".repeat(5), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn combines_short_paragraphs_that_fit_together_into_one_chunk() {
+        let text = "one\n\ntwo\n\nthree";
+
+        let chunks = split_into_chunks(text, 100);
+
+        assert_eq!(chunks, vec![(text.to_string(), true)]);
+    }
+
+    #[test]
+    fn a_paragraph_exactly_at_the_limit_is_not_force_split() {
+        let paragraph = "a".repeat(10);
+
+        let chunks = split_into_chunks(&paragraph, 10);
+
+        assert_eq!(chunks, vec![(paragraph, true)]);
+    }
+
+    #[test]
+    fn a_paragraph_that_would_overflow_the_running_chunk_starts_a_new_one() {
+        let text = format!("{}\n\n{}", "a".repeat(6), "b".repeat(6));
+
+        let chunks = split_into_chunks(&text, 10);
+
+        assert_eq!(chunks, vec![("a".repeat(6), true), ("b".repeat(6), true)]);
+    }
+
+    #[test]
+    fn counts_unicode_characters_rather_than_bytes() {
+        let paragraph = "字".repeat(15);
+
+        let chunks = split_into_chunks(&paragraph, 10);
+
+        assert_eq!(
+            chunks,
+            vec![("字".repeat(10), true), ("字".repeat(5), false)]
+        );
+    }
+}
+
+#[tauri::command]
+async fn translate_document(
+    source_path: Option<String>,
+    content: Option<Vec<u8>>,
+    filename: String,
+    target_lang: Option<String>,
+) -> Result<(String, String), String> {
+    log_to_file(&format!("Attempting to translate document: {}", filename));
+
+    let target_lang = match target_lang {
+        Some(lang) => parse_target_lang(&lang)?,
+        None => Language::English,
+    };
+
+    let bytes = match (source_path, content) {
+        (Some(path), _) => std::fs::read(&path).map_err(|e| e.to_string())?,
+        (None, Some(bytes)) => bytes,
+        (None, None) => return Err("Either source_path or content must be provided".to_string()),
+    };
+
+    let text = String::from_utf8(bytes).map_err(|e| e.to_string())?;
+
+    let chunks = split_into_chunks(&text, MAX_CHUNK_CHARS);
+    let mut translated_chunks = Vec::with_capacity(chunks.len());
+
+    for (chunk, starts_paragraph) in chunks {
+        let translated = translate_text_cached(&chunk, target_lang)
+            .await
+            .map_err(|e| e.to_string())?;
+        translated_chunks.push((translated, starts_paragraph));
+    }
+
+    // Only reinsert the `\n\n` separator where a paragraph break actually was —
+    // forced mid-paragraph splits rejoin with nothing, matching the source.
+    let mut translated_content = String::new();
+    for (i, (chunk, starts_paragraph)) in translated_chunks.iter().enumerate() {
+        if i > 0 && *starts_paragraph {
+            translated_content.push_str("\n\n");
+        }
+        translated_content.push_str(chunk);
+    }
+
+    let translated_filename = translate_filename_lang(&filename, target_lang).await?;
+    flush_translation_cache();
+
+    let temp_path = std::env::temp_dir().join(&translated_filename);
+    std::fs::write(&temp_path, translated_content).map_err(|e| e.to_string())?;
+
+    log_to_file(&format!("Translated document written to: {}", temp_path.display()));
+
+    Ok((temp_path.to_string_lossy().into_owned(), translated_filename))
+}
+
 #[tauri::command]
 async fn create_zip_file(
     files: Vec<(String, String)>,
@@ -162,8 +809,10 @@ async fn create_zip_file(
         let mut src_file = File::open(&src_path).map_err(|e| e.to_string())?;
         let mut buffer = Vec::new();
         src_file.read_to_end(&mut buffer).map_err(|e| e.to_string())?;
-        
-        zip.start_file(filename, options).map_err(|e| e.to_string())?;
+
+        // The zip format requires `/` separators even for nested relative paths.
+        let entry_name = filename.replace('\\', "/");
+        zip.start_file(entry_name, options).map_err(|e| e.to_string())?;
         zip.write_all(&buffer).map_err(|e| e.to_string())?;
     }
 
@@ -223,9 +872,13 @@ fn main() {
         })
         .invoke_handler(tauri::generate_handler![
             translate_filename,
+            translate_filenames,
+            translate_path,
+            translate_document,
             create_zip_file,
             create_temp_file,
-            get_temp_dir
+            get_temp_dir,
+            clear_translation_cache
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");